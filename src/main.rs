@@ -1,24 +1,78 @@
 use std::{
+    cell::RefCell,
     cmp, env,
     fs::File,
-    io::{self, BufRead, Stdout, Write},
+    io::{self, BufRead, BufWriter, Stdout, Write},
     path::PathBuf,
+    sync::mpsc::{self, RecvTimeoutError},
+    thread,
     time::Duration,
 };
 
 use crossterm::{
     cursor::{self},
-    event::{poll, read, Event, KeyCode, KeyEvent, KeyModifiers},
+    event::{read, Event, KeyCode, KeyEvent, KeyModifiers},
     execute, queue,
     style::{self},
-    terminal::{self, Clear},
+    terminal::{self, Clear, EnterAlternateScreen, LeaveAlternateScreen},
 };
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+// The number of terminal columns a grapheme cluster occupies: 2 for
+// wide/fullwidth glyphs, 0 for zero-width combiners, 1 otherwise.
+fn cluster_width(cluster: &str) -> usize {
+    UnicodeWidthStr::width(cluster)
+}
+
+// The three character classes word motions step between. A run of one class is
+// a "token"; `w`/`b`/`e` move between tokens, skipping whitespace.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum CharClass {
+    Whitespace,
+    Word,
+    Punct,
+}
+
+// classify a grapheme cluster by its leading scalar value
+fn classify(cluster: &str) -> CharClass {
+    match cluster.chars().next() {
+        Some(c) if c.is_whitespace() => CharClass::Whitespace,
+        Some(c) if c.is_alphanumeric() || c == '_' => CharClass::Word,
+        Some(_) => CharClass::Punct,
+        None => CharClass::Whitespace,
+    }
+}
 
 struct TermInfo {
     alive: bool,
     stdout: Stdout,
     width: usize,
     height: usize,
+    // the cells last painted to the screen, one inner Vec per visible row.
+    // An empty outer Vec (or a row whose width no longer matches) is the
+    // sentinel that forces a full repaint of that row.
+    prev_frame: Vec<Vec<Cell>>,
+}
+
+// One terminal column. A wide grapheme occupies its start column plus a
+// trailing `continuation` column that is covered by the glyph and never
+// printed. `reverse` renders the cell in reverse video to mark search matches.
+#[derive(Clone, PartialEq, Eq)]
+struct Cell {
+    grapheme: String,
+    reverse: bool,
+    continuation: bool,
+}
+
+impl Cell {
+    fn blank() -> Cell {
+        Cell {
+            grapheme: String::from(" "),
+            reverse: false,
+            continuation: false,
+        }
+    }
 }
 
 impl TermInfo {
@@ -26,8 +80,10 @@ impl TermInfo {
         // get handle to stdout()
         let mut stdout = io::stdout();
 
-        // enable raw mode
+        // enable raw mode and switch to the alternate screen so the user's
+        // shell scrollback is left untouched while we run
         terminal::enable_raw_mode()?;
+        execute!(stdout, EnterAlternateScreen)?;
 
         // clear terminal
         execute!(stdout, Clear(terminal::ClearType::All))?;
@@ -43,38 +99,65 @@ impl TermInfo {
             stdout,
             width: term_width as usize,
             height: term_height as usize,
+            prev_frame: vec![],
         })
     }
 
-    fn update_size(&mut self) -> Result<(), io::Error> {
-        let (term_width, term_height) = terminal::size()?;
-
-        self.width = term_width as usize;
-        self.height = term_height as usize;
+    // adopt the dimensions carried by a `Event::Resize`, driven off the event
+    // stream rather than polling `terminal::size()` every loop iteration
+    fn resize(&mut self, width: usize, height: usize) {
+        // on a resize the previous frame no longer maps onto the screen, so
+        // drop it to force a full repaint on the next draw
+        if width != self.width || height != self.height {
+            self.prev_frame.clear();
+        }
 
-        Ok(())
+        self.width = width;
+        self.height = height;
     }
 }
 
 impl Drop for TermInfo {
     fn drop(&mut self) {
-        // disable raw mode
+        // leave the alternate screen to restore whatever the user had before,
+        // then disable raw mode
+        execute!(self.stdout, LeaveAlternateScreen).unwrap();
         terminal::disable_raw_mode().unwrap();
+    }
+}
 
-        // clear terminal
-        execute!(self.stdout, Clear(terminal::ClearType::All)).unwrap();
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Mode {
+    Normal,
+    Insert,
+    Search,
+}
 
-        // move cursor to top left
-        execute!(self.stdout, cursor::MoveTo(0, 0)).unwrap();
-    }
+// Incremental search state. `query` doubles as the live query while typing and
+// the confirmed query that `n`/`N` step through; `origin` is where the cursor
+// sat when `/` was pressed: each keystroke re-scans from it, and `Esc` returns
+// the cursor to it.
+#[derive(Default, Debug)]
+struct Search {
+    query: String,
+    origin: (usize, usize),
 }
 
+// The cursor's logical position is a (row, grapheme index) pair. `col` is the
+// grapheme index within the row so `h`/`l` step one cluster; the on-screen
+// column is derived from the cumulative display width of the clusters to its
+// left. `ren_row`/`ren_col` are the vertical (row) and horizontal (display
+// column) scroll offsets.
 #[derive(Default, Debug)]
 struct Cursor {
     col: usize,
     row: usize,
     ren_col: usize,
     ren_row: usize,
+    // set after a lone `g`, so the next `g` completes the `gg` motion
+    pending_g: bool,
+    // soft line-wrapping view mode instead of horizontal scrolling
+    wrap: bool,
 }
 
 impl Cursor {
@@ -84,6 +167,8 @@ impl Cursor {
             row: 0,
             ren_col: 0,
             ren_row: 0,
+            pending_g: false,
+            wrap: false,
         }
     }
 
@@ -91,20 +176,236 @@ impl Cursor {
         &mut self,
         key_event: KeyEvent,
         term_info: &mut TermInfo,
-        text: &Text,
+        text: &mut Text,
+        mode: &mut Mode,
+        search: &mut Search,
+    ) -> Result<(), io::Error> {
+        match mode {
+            Mode::Normal => self.handle_normal(key_event, term_info, text, mode, search)?,
+            Mode::Insert => self.handle_insert(key_event, term_info, text, mode)?,
+            Mode::Search => self.handle_search(key_event, term_info, text, mode, search)?,
+        }
+        Ok(())
+    }
+
+    fn handle_normal(
+        &mut self,
+        key_event: KeyEvent,
+        term_info: &mut TermInfo,
+        text: &mut Text,
+        mode: &mut Mode,
+        search: &mut Search,
     ) -> Result<(), io::Error> {
-        if key_event.modifiers == KeyModifiers::NONE {
+        // a pending `gg` only survives a literal follow-up `g`; any other key
+        // (command, mode switch, or control chord) cancels the armed motion
+        if key_event.code != KeyCode::Char('g') || key_event.modifiers != KeyModifiers::NONE {
+            self.pending_g = false;
+        }
+
+        if key_event.modifiers == KeyModifiers::CONTROL {
+            match key_event.code {
+                // save the buffer back to disk. `Ctrl-S` is the only save
+                // binding: plain `w` is the word motion (see `handle_cursor_move`),
+                // and a `:w`-style command line is out of scope here.
+                KeyCode::Char('s') => text.save()?,
+                // redo
+                KeyCode::Char('r') => {
+                    if let Some((row, col)) = text.redo_edit() {
+                        self.set_position(row, col, term_info, text)?;
+                    }
+                }
+                _ => (),
+            }
+            return Ok(());
+        }
+
+        // SHIFT is allowed through so capitalised commands (e.g. `N`) still match
+        if key_event.modifiers == KeyModifiers::NONE
+            || key_event.modifiers == KeyModifiers::SHIFT
+        {
             match key_event.code {
-                // quite
+                // quit
                 KeyCode::Char('q') => {
                     term_info.alive = false;
                     return Ok(());
                 }
 
-                // cursor movement
-                _ => self.handle_cursor_move(key_event.code, term_info, text)?,
+                // enter insert mode at the cursor
+                KeyCode::Char('i') => *mode = Mode::Insert,
+
+                // enter insert mode one column past the cursor
+                KeyCode::Char('a') => {
+                    if !text.rows.is_empty() && !self.is_past_row_end(text) {
+                        self.col += 1;
+                        self.place_cursor(term_info, text)?;
+                    }
+                    *mode = Mode::Insert;
+                }
+
+                // open a new row below and enter insert mode
+                KeyCode::Char('o') => {
+                    if text.rows.is_empty() {
+                        text.open_row(0);
+                    } else {
+                        let row = self.row_index();
+                        let len = text.rows[row].clusters.len();
+                        text.split_row(row, len);
+                        self.move_down(term_info, text);
+                    }
+                    self.move_cursor_start_of_row(term_info)?;
+                    *mode = Mode::Insert;
+                }
+
+                // undo / redo
+                KeyCode::Char('u') => {
+                    if let Some((row, col)) = text.undo_edit() {
+                        self.set_position(row, col, term_info, text)?;
+                    }
+                }
+
+                // toggle soft line-wrapping
+                KeyCode::Char('z') => {
+                    self.wrap = !self.wrap;
+                    self.ren_col = 0;
+                    term_info.prev_frame.clear();
+                    self.place_cursor(term_info, text)?;
+                }
+
+                // start an incremental search
+                KeyCode::Char('/') => {
+                    search.query.clear();
+                    search.origin = (self.row_index(), self.col_index());
+                    *mode = Mode::Search;
+                }
+
+                // jump to the next / previous match of the confirmed query
+                KeyCode::Char('n') => {
+                    if !search.query.is_empty() {
+                        let (row, col) = (self.row_index(), self.col_index());
+                        if let Some(hit) = text.find_match(row, col + 1, &search.query) {
+                            self.set_position(hit.0, hit.1, term_info, text)?;
+                        }
+                    }
+                }
+                KeyCode::Char('N') => {
+                    if !search.query.is_empty() {
+                        let (row, col) = (self.row_index(), self.col_index());
+                        if let Some(hit) = text.rfind_match(row, col, &search.query) {
+                            self.set_position(hit.0, hit.1, term_info, text)?;
+                        }
+                    }
+                }
+
+                // cursor movement breaks any in-progress coalesced insert run
+                _ => {
+                    text.coalesce = None;
+                    self.handle_cursor_move(key_event.code, term_info, text)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn handle_insert(
+        &mut self,
+        key_event: KeyEvent,
+        term_info: &mut TermInfo,
+        text: &mut Text,
+        mode: &mut Mode,
+    ) -> Result<(), io::Error> {
+        match key_event.code {
+            // back to normal mode
+            KeyCode::Esc => {
+                *mode = Mode::Normal;
+                text.coalesce = None;
+                // vim nudges the cursor left when leaving insert mode
+                if self.col_index() > 0 {
+                    self.col -= 1;
+                }
+            }
+
+            // insert a printable character (control chords are ignored so a
+            // stray `Ctrl-S`/`Ctrl-R` doesn't insert a literal letter)
+            KeyCode::Char(c) if !key_event.modifiers.contains(KeyModifiers::CONTROL) => {
+                if text.rows.is_empty() {
+                    text.open_row(0);
+                }
+                let (row, col) = (self.row_index(), self.col_index());
+                text.insert_char(row, col, c);
+                self.col += 1;
+            }
+
+            // split the current row at the cursor
+            KeyCode::Enter => {
+                if text.rows.is_empty() {
+                    text.open_row(0);
+                }
+                let (row, col) = (self.row_index(), self.col_index());
+                text.split_row(row, col);
+                self.move_down(term_info, text);
+                self.col = 0;
+            }
+
+            // delete the character before the cursor, joining rows at column 0
+            KeyCode::Backspace => {
+                let (row, col) = (self.row_index(), self.col_index());
+                if col > 0 {
+                    text.delete_char(row, col - 1);
+                    self.col -= 1;
+                } else if row > 0 {
+                    let join_col = text.rows[row - 1].clusters.len();
+                    text.join_row(row);
+                    self.move_up();
+                    self.col = join_col;
+                }
             }
+
+            _ => (),
         }
+
+        self.place_cursor(term_info, text)?;
+
+        Ok(())
+    }
+
+    fn handle_search(
+        &mut self,
+        key_event: KeyEvent,
+        term_info: &mut TermInfo,
+        text: &mut Text,
+        mode: &mut Mode,
+        search: &mut Search,
+    ) -> Result<(), io::Error> {
+        match key_event.code {
+            // confirm the query and return to normal mode, keeping it for n/N
+            KeyCode::Enter => {
+                *mode = Mode::Normal;
+                return Ok(());
+            }
+            // abandon the search and restore the pre-search cursor position
+            KeyCode::Esc => {
+                let (row, col) = search.origin;
+                self.set_position(row, col, term_info, text)?;
+                search.query.clear();
+                *mode = Mode::Normal;
+                return Ok(());
+            }
+            KeyCode::Char(c) => search.query.push(c),
+            KeyCode::Backspace => {
+                search.query.pop();
+            }
+            _ => return Ok(()),
+        }
+
+        // re-run the search from the origin on every keystroke; leave the cursor
+        // where it was for an empty query or no match
+        if !search.query.is_empty() {
+            let (row, col) = search.origin;
+            if let Some((hit_row, hit_col)) = text.find_match(row, col, &search.query) {
+                self.set_position(hit_row, hit_col, term_info, text)?;
+            }
+        }
+
         Ok(())
     }
 
@@ -113,7 +414,7 @@ impl Cursor {
     }
 
     fn col_index(&self) -> usize {
-        self.col + self.ren_col
+        self.col
     }
 
     fn is_past_last_row(&self, text: &Text) -> bool {
@@ -121,34 +422,202 @@ impl Cursor {
     }
 
     fn is_past_row_end(&self, text: &Text) -> bool {
-        let row_len = text.rows[self.row_index()].chars.len();
+        let row_len = text.rows[self.row_index()].clusters.len();
         self.col_index() as isize >= row_len as isize
     }
 
+    // the cursor's display column: the summed width of the clusters to its left
+    fn display_col(&self, text: &Text) -> usize {
+        if text.rows.is_empty() {
+            return 0;
+        }
+        text.rows[self.row_index()].display_col(self.col)
+    }
+
+    // move down one row, scrolling vertically when needed
+    fn move_down(&mut self, term_info: &TermInfo, text: &Text) {
+        if !self.is_past_last_row(text) {
+            if self.row < term_info.height - 1 {
+                self.row += 1;
+            } else {
+                self.ren_row += 1;
+            }
+        }
+    }
+
+    // move up one row, scrolling vertically when needed
+    fn move_up(&mut self) {
+        if self.row > 0 {
+            self.row -= 1;
+        } else if self.ren_row > 0 {
+            self.ren_row -= 1;
+        }
+    }
+
+    // place the cursor at an absolute (row, col), scrolling vertically so the
+    // row is visible; horizontal scroll is settled by `place_cursor`
+    fn set_position(
+        &mut self,
+        row: usize,
+        col: usize,
+        term_info: &mut TermInfo,
+        text: &Text,
+    ) -> Result<(), io::Error> {
+        if row < term_info.height {
+            self.row = row;
+            self.ren_row = 0;
+        } else {
+            self.row = term_info.height - 1;
+            self.ren_row = row - (term_info.height - 1);
+        }
+        self.col = col;
+        self.place_cursor(term_info, text)
+    }
+
+    fn move_cursor_start_of_row(&mut self, term_info: &mut TermInfo) -> Result<(), io::Error> {
+        self.col = 0;
+        self.ren_col = 0;
+
+        execute!(term_info.stdout, cursor::MoveTo(0, self.row as u16))?;
+
+        Ok(())
+    }
+
     fn move_cursor_end_of_row(
         &mut self,
         term_info: &mut TermInfo,
         text: &Text,
     ) -> Result<(), io::Error> {
-        let row_len = text.rows[self.row_index()].chars.len();
+        self.col = text.rows[self.row_index()].clusters.len();
+        self.place_cursor(term_info, text)
+    }
+
+    // settle the scroll from the cursor's position, then move the terminal
+    // cursor to the matching on-screen cell. In wrap mode the column is always
+    // on screen and the visual row is derived from the per-row wrap points; in
+    // scroll mode the horizontal offset tracks the cursor's display column.
+    fn place_cursor(&mut self, term_info: &mut TermInfo, text: &Text) -> Result<(), io::Error> {
+        if self.wrap && !text.rows.is_empty() {
+            let width = cmp::max(1, term_info.width);
+            let ri = self.row_index();
+
+            // keep the cursor's logical row at or below the top of the viewport
+            if ri < self.ren_row {
+                self.ren_row = ri;
+            }
+
+            // scroll down by whole logical rows until the cursor's visual line
+            // fits; keep `row` in step with `ren_row` so `row_index()` stays at
+            // the target row as `wrap_screen_pos` re-measures each iteration
+            loop {
+                self.row = ri - self.ren_row;
+                let (y, x) = self.wrap_screen_pos(text, width);
+                if y < term_info.height || self.ren_row >= ri {
+                    execute!(term_info.stdout, cursor::MoveTo(x as u16, y as u16))?;
+                    return Ok(());
+                }
+                self.ren_row += 1;
+            }
+        }
 
-        self.col = text.rows[self.row_index()].chars.len();
-        self.ren_col = cmp::max(0, row_len as i16 - term_info.width as i16) as usize;
+        let rx = self.display_col(text);
+        if rx < self.ren_col {
+            self.ren_col = rx;
+        } else if term_info.width > 0 && rx >= self.ren_col + term_info.width {
+            self.ren_col = rx - term_info.width + 1;
+        }
 
+        let screen_x = rx - self.ren_col;
         execute!(
             term_info.stdout,
-            cursor::MoveTo(self.col as u16, self.row as u16)
+            cursor::MoveTo(screen_x as u16, self.row as u16)
         )?;
 
         Ok(())
     }
 
+    // the (visual row, column) the cursor occupies within the wrapped viewport
+    fn wrap_screen_pos(&self, text: &Text, width: usize) -> (usize, usize) {
+        let ri = self.row_index();
+        let mut y = 0;
+        for r in self.ren_row..ri {
+            y += text.wrap_starts(r, width).len();
+        }
+
+        let starts = text.wrap_starts(ri, width);
+        let seg = starts.iter().rposition(|&s| s <= self.col).unwrap_or(0);
+        y += seg;
+
+        let row = &text.rows[ri];
+        let x = row.display_col(self.col) - row.display_col(starts[seg]);
+        (y, x)
+    }
+
+    // set the logical row, preferring to keep the current top-of-viewport
+    fn set_row_index(&mut self, ri: usize) {
+        if ri >= self.ren_row {
+            self.row = ri - self.ren_row;
+        } else {
+            self.ren_row = ri;
+            self.row = 0;
+        }
+    }
+
+    // move one visual line down, retaining the offset within the wrapped line
+    fn visual_down(&self, text: &Text, width: usize) -> (usize, usize) {
+        let ri = self.row_index();
+        let len = text.rows[ri].clusters.len();
+        let starts = text.wrap_starts(ri, width);
+        let seg = starts.iter().rposition(|&s| s <= self.col).unwrap_or(0);
+        let off = self.col - starts[seg];
+
+        if seg + 1 < starts.len() {
+            let next_start = starts[seg + 1];
+            let next_end = starts.get(seg + 2).copied().unwrap_or(len);
+            (ri, cmp::min(next_start + off, next_end.saturating_sub(1)))
+        } else if ri + 1 < text.rows.len() {
+            let next = ri + 1;
+            let len = text.rows[next].clusters.len();
+            let next_starts = text.wrap_starts(next, width);
+            let end = next_starts.get(1).copied().unwrap_or(len);
+            (next, cmp::min(off, end.saturating_sub(1).min(len)))
+        } else {
+            (ri, self.col)
+        }
+    }
+
+    // move one visual line up, retaining the offset within the wrapped line
+    fn visual_up(&self, text: &Text, width: usize) -> (usize, usize) {
+        let ri = self.row_index();
+        let starts = text.wrap_starts(ri, width);
+        let seg = starts.iter().rposition(|&s| s <= self.col).unwrap_or(0);
+        let off = self.col - starts[seg];
+
+        if seg > 0 {
+            let prev_start = starts[seg - 1];
+            let prev_end = starts[seg];
+            (ri, cmp::min(prev_start + off, prev_end.saturating_sub(1)))
+        } else if ri > 0 {
+            let prev = ri - 1;
+            let len = text.rows[prev].clusters.len();
+            let prev_starts = text.wrap_starts(prev, width);
+            let last_start = *prev_starts.last().unwrap();
+            (prev, cmp::min(last_start + off, len.saturating_sub(1).max(last_start)))
+        } else {
+            (ri, self.col)
+        }
+    }
+
     fn handle_cursor_move(
         &mut self,
         key_code: KeyCode,
         term_info: &mut TermInfo,
         text: &Text,
     ) -> Result<(), io::Error> {
+        // `gg` is a two-key motion: remember a lone `g` and complete it here
+        let pending_g = self.pending_g;
+        self.pending_g = false;
+
         // if text is empty nowhere to move
         if text.rows.is_empty() {
             return Ok(());
@@ -157,82 +626,192 @@ impl Cursor {
         match key_code {
             // UP
             KeyCode::Char('k') => {
-                if self.row > 0 {
-                    self.row -= 1;
-                } else if self.ren_row > 0 {
-                    self.ren_row -= 1;
+                // in wrap mode j/k step one visual line, not one logical row
+                if self.wrap {
+                    let width = cmp::max(1, term_info.width);
+                    let (r, c) = self.visual_up(text, width);
+                    self.set_row_index(r);
+                    self.col = c;
+                    return self.place_cursor(term_info, text);
                 }
 
+                self.move_up();
+
                 // if we are past last char in row move back to last char
                 if self.is_past_row_end(text) {
-                    self.move_cursor_end_of_row(term_info, text)?;
+                    return self.move_cursor_end_of_row(term_info, text);
                 }
             }
             // DOWN
             KeyCode::Char('j') => {
-                if !self.is_past_last_row(text) {
-                    if self.row < term_info.height - 1 {
-                        self.row += 1;
-                    } else {
-                        self.ren_row += 1;
-                    }
+                if self.wrap {
+                    let width = cmp::max(1, term_info.width);
+                    let (r, c) = self.visual_down(text, width);
+                    self.set_row_index(r);
+                    self.col = c;
+                    return self.place_cursor(term_info, text);
                 }
 
+                self.move_down(term_info, text);
+
                 // if we are past last char in row move back to last char
                 if self.is_past_row_end(text) {
-                    self.move_cursor_end_of_row(term_info, text)?;
+                    return self.move_cursor_end_of_row(term_info, text);
                 }
             }
             // LEFT
             KeyCode::Char('h') => {
                 if self.col > 0 {
                     self.col -= 1;
-                } else if self.ren_col > 0 {
-                    self.ren_col -= 1;
                 }
             }
             // RIGHT
             KeyCode::Char('l') => {
                 if !self.is_past_row_end(text) {
-                    if self.col < term_info.width - 1 {
-                        self.col += 1;
-                    } else {
-                        self.ren_col += 1;
-                    }
+                    self.col += 1;
                 }
             }
-            _ => (),
-        }
 
-        // move cursor
-        execute!(
-            term_info.stdout,
-            cursor::MoveTo(self.col as u16, self.row as u16)
-        )?;
+            // first column of the row
+            KeyCode::Char('0') => self.col = 0,
 
-        Ok(())
+            // first non-whitespace column of the row
+            KeyCode::Char('^') => self.col = text.first_non_blank(self.row_index()),
+
+            // last char of the row
+            KeyCode::Char('$') => {
+                let len = text.rows[self.row_index()].clusters.len();
+                self.col = len.saturating_sub(1);
+            }
+
+            // bottom of the buffer
+            KeyCode::Char('G') => {
+                let last = text.rows.len() - 1;
+                return self.set_position(last, 0, term_info, text);
+            }
+
+            // `g` on its own arms `gg`; a second `g` jumps to the top
+            KeyCode::Char('g') => {
+                if pending_g {
+                    return self.set_position(0, 0, term_info, text);
+                }
+                self.pending_g = true;
+                return Ok(());
+            }
+
+            // word motions
+            KeyCode::Char('w') => {
+                let (row, col) = text.word_forward(self.row_index(), self.col_index());
+                return self.set_position(row, col, term_info, text);
+            }
+            KeyCode::Char('b') => {
+                let (row, col) = text.word_backward(self.row_index(), self.col_index());
+                return self.set_position(row, col, term_info, text);
+            }
+            KeyCode::Char('e') => {
+                let (row, col) = text.word_end(self.row_index(), self.col_index());
+                return self.set_position(row, col, term_info, text);
+            }
+
+            _ => return Ok(()),
+        }
+
+        self.place_cursor(term_info, text)
     }
 }
 
 #[derive(Clone, Debug)]
 struct Row {
-    chars: Vec<char>,
+    clusters: Vec<String>,
+}
+
+impl Row {
+    // split a line into grapheme clusters, the editing/rendering unit
+    fn from_str(line: &str) -> Row {
+        Row {
+            clusters: line.graphemes(true).map(String::from).collect(),
+        }
+    }
+
+    // the whole row reassembled into a string, for writing back to disk
+    fn as_string(&self) -> String {
+        self.clusters.concat()
+    }
+
+    // display column of the cluster at grapheme index `idx`
+    fn display_col(&self, idx: usize) -> usize {
+        let idx = cmp::min(idx, self.clusters.len());
+        self.clusters[..idx].iter().map(|c| cluster_width(c)).sum()
+    }
+}
+
+// A reversible edit. Each variant's `apply` returns the variant that undoes it,
+// so a single stack machine drives both editing and undo/redo. The `Run`
+// variants exist so a word typed without moving the cursor collapses into one
+// undo record.
+#[derive(Clone, Debug)]
+enum Edit {
+    Insert { row: usize, col: usize, cluster: String },
+    Delete { row: usize, col: usize, cluster: String },
+    InsertRun { row: usize, col: usize, clusters: Vec<String> },
+    DeleteRun { row: usize, col: usize, clusters: Vec<String> },
+    SplitRow { row: usize, col: usize },
+    JoinRow { row: usize, col: usize },
+}
+
+impl Edit {
+    // the (row, col) the cursor should rest at after this edit is applied
+    fn pos(&self) -> (usize, usize) {
+        match *self {
+            Edit::Insert { row, col, .. } => (row, col + 1),
+            Edit::Delete { row, col, .. } => (row, col),
+            Edit::InsertRun { row, col, ref clusters } => (row, col + clusters.len()),
+            Edit::DeleteRun { row, col, .. } => (row, col),
+            Edit::SplitRow { row, .. } => (row + 1, 0),
+            Edit::JoinRow { row, col } => (row - 1, col),
+        }
+    }
+}
+
+// Per-row soft-wrap break points, memoised for the width they were computed at.
+// `starts[r]` holds the cluster indices that begin each visual line of row `r`;
+// an empty `starts` (or a differing `width`) forces a rebuild, which is how the
+// cache is invalidated on resize and after every edit.
+#[derive(Debug, Default)]
+struct WrapCache {
+    width: usize,
+    starts: Vec<Vec<usize>>,
 }
 
 #[derive(Debug)]
 struct Text {
     rows: Vec<Row>,
+    path: Option<PathBuf>,
+    undo: Vec<Edit>,
+    redo: Vec<Edit>,
+    // (row, col) the next typed cluster must land on to extend the current
+    // coalesced insert run; `None` once the run is broken by a move or other edit
+    coalesce: Option<(usize, usize)>,
+    // memoised soft-wrap break points, rebuilt lazily by `wrap_starts`
+    wrap_cache: RefCell<WrapCache>,
 }
 
 impl Text {
     fn new() -> Result<Text, io::Error> {
-        Ok(Text { rows: vec![] })
+        Ok(Text {
+            rows: vec![],
+            path: None,
+            undo: vec![],
+            redo: vec![],
+            coalesce: None,
+            wrap_cache: RefCell::new(WrapCache::default()),
+        })
     }
 
     fn from_file(path: PathBuf) -> Result<Text, io::Error> {
         let mut text = Text::new()?;
 
-        let file = File::open(path)?;
+        let file = File::open(&path)?;
         let buf_reader = io::BufReader::new(file);
 
         buf_reader.lines().for_each(|line| {
@@ -248,45 +827,656 @@ impl Text {
             }
 
             // write to Text struct
-            text.rows.push(Row {
-                chars: line.chars().collect(),
-            });
+            text.rows.push(Row::from_str(&line));
         });
 
+        text.path = Some(path);
+
         Ok(text)
     }
 
-    fn draw_text(&self, term_info: &mut TermInfo, cursor: &Cursor) -> Result<(), io::Error> {
+    // write the buffer back to the file it was opened from
+    fn save(&self) -> Result<(), io::Error> {
+        let path = match &self.path {
+            Some(path) => path,
+            None => return Ok(()),
+        };
+
+        let file = File::create(path)?;
+        let mut writer = BufWriter::new(file);
+
+        for row in &self.rows {
+            writer.write_all(row.as_string().as_bytes())?;
+            writer.write_all(b"\n")?;
+        }
+
+        writer.flush()
+    }
+
+    // find the first occurrence of `query` at or after cluster index `from`
+    fn row_find(clusters: &[String], query: &[String], from: usize) -> Option<usize> {
+        if query.is_empty() || query.len() > clusters.len() {
+            return None;
+        }
+        let last = clusters.len() - query.len();
+        (from..=last).find(|&i| clusters[i..i + query.len()] == query[..])
+    }
+
+    // find the last occurrence of `query` strictly before cluster index `before`
+    fn row_rfind(clusters: &[String], query: &[String], before: usize) -> Option<usize> {
+        if query.is_empty() || query.len() > clusters.len() {
+            return None;
+        }
+        let last = clusters.len() - query.len();
+        (0..=last)
+            .rev()
+            .find(|&i| i < before && clusters[i..i + query.len()] == query[..])
+    }
+
+    // first match of `query` at or after (start_row, start_col), wrapping to the top
+    fn find_match(&self, start_row: usize, start_col: usize, query: &str) -> Option<(usize, usize)> {
+        let q: Vec<String> = query.graphemes(true).map(String::from).collect();
+        if self.rows.is_empty() || q.is_empty() {
+            return None;
+        }
+
+        let n = self.rows.len();
+        let start_row = start_row % n;
+        for step in 0..=n {
+            let row = (start_row + step) % n;
+            let from = if step == 0 { start_col } else { 0 };
+            if let Some(col) = Self::row_find(&self.rows[row].clusters, &q, from) {
+                return Some((row, col));
+            }
+        }
+
+        None
+    }
+
+    // last match of `query` strictly before (start_row, start_col), wrapping to the bottom
+    fn rfind_match(&self, start_row: usize, start_col: usize, query: &str) -> Option<(usize, usize)> {
+        let q: Vec<String> = query.graphemes(true).map(String::from).collect();
+        if self.rows.is_empty() || q.is_empty() {
+            return None;
+        }
+
+        let n = self.rows.len();
+        let start_row = start_row % n;
+        for step in 0..=n {
+            let row = (start_row + n - step) % n;
+            let before = if step == 0 { start_col } else { usize::MAX };
+            if let Some(col) = Self::row_rfind(&self.rows[row].clusters, &q, before) {
+                return Some((row, col));
+            }
+        }
+
+        None
+    }
+
+    // class of the cluster at (row, col); positions at or past a row's end
+    // (including empty rows) count as whitespace so they form word boundaries
+    fn class_at(&self, row: usize, col: usize) -> CharClass {
+        match self.rows[row].clusters.get(col) {
+            Some(cluster) => classify(cluster),
+            None => CharClass::Whitespace,
+        }
+    }
+
+    // step one cluster forward, rolling onto the next row at a row's end
+    fn step_fwd(&self, row: usize, col: usize) -> Option<(usize, usize)> {
+        if col + 1 < self.rows[row].clusters.len() {
+            Some((row, col + 1))
+        } else if row + 1 < self.rows.len() {
+            Some((row + 1, 0))
+        } else {
+            None
+        }
+    }
+
+    // step one cluster backward, rolling onto the previous row at column 0
+    fn step_bwd(&self, row: usize, col: usize) -> Option<(usize, usize)> {
+        if col > 0 {
+            Some((row, col - 1))
+        } else if row > 0 {
+            let prev_len = self.rows[row - 1].clusters.len();
+            Some((row - 1, prev_len.saturating_sub(1)))
+        } else {
+            None
+        }
+    }
+
+    // advance to the last cluster of the current token (no-op on whitespace)
+    fn end_of_token(&self, row: usize, col: usize) -> (usize, usize) {
+        let class = self.class_at(row, col);
+        if class == CharClass::Whitespace {
+            return (row, col);
+        }
+        let (mut row, mut col) = (row, col);
+        while let Some((nr, nc)) = self.step_fwd(row, col) {
+            if nr == row && self.class_at(nr, nc) == class {
+                row = nr;
+                col = nc;
+            } else {
+                break;
+            }
+        }
+        (row, col)
+    }
+
+    // `w`: start of the next token, skipping whitespace and crossing rows
+    fn word_forward(&self, row: usize, col: usize) -> (usize, usize) {
+        let (mut row, mut col) = self.end_of_token(row, col);
+        match self.step_fwd(row, col) {
+            Some((r, c)) => {
+                row = r;
+                col = c;
+            }
+            None => return (row, col),
+        }
+        while self.class_at(row, col) == CharClass::Whitespace {
+            match self.step_fwd(row, col) {
+                Some((r, c)) => {
+                    row = r;
+                    col = c;
+                }
+                None => break,
+            }
+        }
+        (row, col)
+    }
+
+    // `e`: end of the next token, skipping whitespace and crossing rows
+    fn word_end(&self, row: usize, col: usize) -> (usize, usize) {
+        let (mut row, mut col) = match self.step_fwd(row, col) {
+            Some(p) => p,
+            None => return (row, col),
+        };
+        while self.class_at(row, col) == CharClass::Whitespace {
+            match self.step_fwd(row, col) {
+                Some((r, c)) => {
+                    row = r;
+                    col = c;
+                }
+                None => return (row, col),
+            }
+        }
+        self.end_of_token(row, col)
+    }
+
+    // `b`: start of the current or previous token, crossing rows
+    fn word_backward(&self, row: usize, col: usize) -> (usize, usize) {
+        let (mut row, mut col) = match self.step_bwd(row, col) {
+            Some(p) => p,
+            None => return (row, col),
+        };
+        while self.class_at(row, col) == CharClass::Whitespace {
+            match self.step_bwd(row, col) {
+                Some((r, c)) => {
+                    row = r;
+                    col = c;
+                }
+                None => return (row, col),
+            }
+        }
+        let class = self.class_at(row, col);
+        while let Some((pr, pc)) = self.step_bwd(row, col) {
+            if pr == row && self.class_at(pr, pc) == class {
+                row = pr;
+                col = pc;
+            } else {
+                break;
+            }
+        }
+        (row, col)
+    }
+
+    // `^`: the first non-whitespace cluster of `row`, or column 0 if none
+    fn first_non_blank(&self, row: usize) -> usize {
+        self.rows[row]
+            .clusters
+            .iter()
+            .position(|c| classify(c) != CharClass::Whitespace)
+            .unwrap_or(0)
+    }
+
+    // insert an empty row at `row`, clamped to the end of the buffer. Used only
+    // to bootstrap the first row of an empty buffer, so it is not recorded.
+    fn open_row(&mut self, row: usize) {
+        let row = cmp::min(row, self.rows.len());
+        self.rows.insert(row, Row { clusters: vec![] });
+    }
+
+    // perform `edit`, mutating `rows`, and return the edit that reverses it.
+    // Apart from loading a file and the unrecorded `open_row` bootstrap, this
+    // is the only code that touches `rows`; every user edit and every
+    // undo/redo step funnels through here.
+    fn apply(&mut self, edit: Edit) -> Edit {
+        // any mutation can shift wrap points, so drop the memoised breaks
+        self.wrap_cache.borrow_mut().starts.clear();
+        match edit {
+            Edit::Insert { row, col, cluster } => {
+                let col = cmp::min(col, self.rows[row].clusters.len());
+                self.rows[row].clusters.insert(col, cluster.clone());
+                Edit::Delete { row, col, cluster }
+            }
+            Edit::Delete { row, col, cluster } => {
+                debug_assert_eq!(self.rows[row].clusters[col], cluster);
+                self.rows[row].clusters.remove(col);
+                Edit::Insert { row, col, cluster }
+            }
+            Edit::InsertRun { row, col, clusters } => {
+                let col = cmp::min(col, self.rows[row].clusters.len());
+                for (i, cluster) in clusters.iter().enumerate() {
+                    self.rows[row].clusters.insert(col + i, cluster.clone());
+                }
+                Edit::DeleteRun { row, col, clusters }
+            }
+            Edit::DeleteRun { row, col, clusters } => {
+                for _ in 0..clusters.len() {
+                    self.rows[row].clusters.remove(col);
+                }
+                Edit::InsertRun { row, col, clusters }
+            }
+            Edit::SplitRow { row, col } => {
+                let col = cmp::min(col, self.rows[row].clusters.len());
+                let tail = self.rows[row].clusters.split_off(col);
+                self.rows.insert(row + 1, Row { clusters: tail });
+                Edit::JoinRow { row: row + 1, col }
+            }
+            Edit::JoinRow { row, col } => {
+                let mut tail = self.rows.remove(row);
+                self.rows[row - 1].clusters.append(&mut tail.clusters);
+                Edit::SplitRow { row: row - 1, col }
+            }
+        }
+    }
+
+    // run a user edit: apply it, record its inverse on the undo stack, and drop
+    // the redo stack now that history has diverged
+    fn edit(&mut self, edit: Edit) {
+        let inverse = self.apply(edit);
+        self.undo.push(inverse);
+        self.redo.clear();
+    }
+
+    // insert `ch` into `row` at grapheme index `col`, coalescing a run of
+    // adjacent inserts into one undo record
+    fn insert_char(&mut self, row: usize, col: usize, ch: char) {
+        let cluster = ch.to_string();
+        if self.coalesce == Some((row, col)) {
+            // extend the DeleteRun/Delete record sitting on top of undo
+            self.apply(Edit::Insert {
+                row,
+                col,
+                cluster: cluster.clone(),
+            });
+            match self.undo.last_mut() {
+                Some(Edit::DeleteRun { clusters, .. }) => clusters.push(cluster),
+                Some(top @ Edit::Delete { .. }) => {
+                    if let Edit::Delete { row, col, cluster: first } = top.clone() {
+                        *top = Edit::DeleteRun {
+                            row,
+                            col,
+                            clusters: vec![first, cluster],
+                        };
+                    }
+                }
+                _ => unreachable!("coalesce set without a matching undo record"),
+            }
+            self.redo.clear();
+        } else {
+            self.edit(Edit::Insert { row, col, cluster });
+        }
+        self.coalesce = Some((row, col + 1));
+    }
+
+    // remove the cluster at `col` from `row`
+    fn delete_char(&mut self, row: usize, col: usize) {
+        if col < self.rows[row].clusters.len() {
+            let cluster = self.rows[row].clusters[col].clone();
+            self.edit(Edit::Delete { row, col, cluster });
+        }
+        self.coalesce = None;
+    }
+
+    // split `row` at `col`, pushing the tail onto a freshly inserted row below
+    fn split_row(&mut self, row: usize, col: usize) {
+        self.edit(Edit::SplitRow { row, col });
+        self.coalesce = None;
+    }
+
+    // join `row` onto the end of the preceding row
+    fn join_row(&mut self, row: usize) {
+        if row == 0 || row >= self.rows.len() {
+            return;
+        }
+        let col = self.rows[row - 1].clusters.len();
+        self.edit(Edit::JoinRow { row, col });
+        self.coalesce = None;
+    }
+
+    // pop the most recent edit, reverse it, and return where the cursor belongs
+    fn undo_edit(&mut self) -> Option<(usize, usize)> {
+        let edit = self.undo.pop()?;
+        let pos = edit.pos();
+        let inverse = self.apply(edit);
+        self.redo.push(inverse);
+        self.coalesce = None;
+        Some(pos)
+    }
+
+    // re-apply the most recently undone edit
+    fn redo_edit(&mut self) -> Option<(usize, usize)> {
+        let edit = self.redo.pop()?;
+        let pos = edit.pos();
+        let inverse = self.apply(edit);
+        self.undo.push(inverse);
+        self.coalesce = None;
+        Some(pos)
+    }
+
+    // the grapheme indices on `row` that begin an occurrence of `query`,
+    // expanded to a per-cluster "is highlighted" flag
+    fn highlights(&self, row: usize, query: &[String]) -> Vec<bool> {
+        let clusters = &self.rows[row].clusters;
+        let mut flags = vec![false; clusters.len()];
+        if query.is_empty() {
+            return flags;
+        }
+
+        let mut from = 0;
+        while let Some(col) = Self::row_find(clusters, query, from) {
+            for flag in flags.iter_mut().skip(col).take(query.len()) {
+                *flag = true;
+            }
+            from = col + query.len();
+        }
+
+        flags
+    }
+
+    // the cluster indices that begin each visual line of `row` when soft-wrapped
+    // at `width` columns, memoised in `wrap_cache`. The cache is rebuilt whenever
+    // the width changes or an edit has cleared it.
+    fn wrap_starts(&self, row: usize, width: usize) -> Vec<usize> {
+        let width = cmp::max(1, width);
+        let mut cache = self.wrap_cache.borrow_mut();
+        if cache.width != width || cache.starts.len() != self.rows.len() {
+            cache.width = width;
+            cache.starts = self
+                .rows
+                .iter()
+                .map(|r| Self::compute_wrap(&r.clusters, width))
+                .collect();
+        }
+        cache.starts[row].clone()
+    }
+
+    // compute the wrap points for a single row: the cluster index that begins
+    // each visual line when laid out by display width at `width` columns. Breaks
+    // prefer the position just after the last space or dash before the boundary,
+    // falling back to a hard break between clusters, mid-token, when a single
+    // token overflows the line. The list always begins with 0.
+    fn compute_wrap(clusters: &[String], width: usize) -> Vec<usize> {
+        let mut starts = vec![0];
+
+        let mut line_start = 0;
+        let mut dc = 0;
+        let mut last_break: Option<usize> = None;
+        let mut i = 0;
+        while i < clusters.len() {
+            let w = cluster_width(&clusters[i]);
+
+            // the cluster would spill past the right edge: start a new visual
+            // line, rewinding to the last space/dash when there was one
+            if dc + w > width && i > line_start {
+                let brk = last_break.filter(|&b| b > line_start).unwrap_or(i);
+                starts.push(brk);
+                line_start = brk;
+                dc = clusters[brk..i].iter().map(|c| cluster_width(c)).sum();
+                last_break = None;
+            }
+
+            dc += w;
+            if matches!(clusters[i].chars().next(), Some(' ') | Some('-')) {
+                last_break = Some(i + 1);
+            }
+            i += 1;
+        }
+
+        starts
+    }
+
+    // render the search prompt onto the status (bottom) row, shared by the
+    // horizontal-scroll and soft-wrap frame builders
+    fn draw_search_prompt(frame: &mut [Vec<Cell>], term_info: &TermInfo, mode: Mode, search: &Search) {
+        if mode != Mode::Search || term_info.height == 0 {
+            return;
+        }
+        let prompt: Vec<char> = format!("/{}", search.query).chars().collect();
+        let status = frame.last_mut().unwrap();
+        for cell in status.iter_mut() {
+            *cell = Cell::blank();
+        }
+        for (i, ch) in prompt.iter().take(term_info.width).enumerate() {
+            status[i].grapheme = ch.to_string();
+        }
+    }
+
+    // build the frame in soft-wrap mode: each logical row contributes one visual
+    // line per wrap segment, laid out from column 0 with no horizontal scroll.
+    fn render_wrapped(
+        &self,
+        term_info: &TermInfo,
+        cursor: &Cursor,
+        mode: Mode,
+        search: &Search,
+    ) -> Vec<Vec<Cell>> {
+        let width = cmp::max(1, term_info.width);
+        let query: Vec<String> = search.query.graphemes(true).map(String::from).collect();
+
+        let mut frame = Vec::with_capacity(term_info.height);
+        let mut row_index = cursor.ren_row;
+        let mut seg = 0;
+        let mut starts = if row_index < self.rows.len() {
+            self.wrap_starts(row_index, width)
+        } else {
+            vec![0]
+        };
+
+        for _ in 0..term_info.height {
+            let mut line = vec![Cell::blank(); term_info.width];
+
+            if row_index < self.rows.len() {
+                let row = &self.rows[row_index];
+                let flags = self.highlights(row_index, &query);
+                let start = starts[seg];
+                let end = starts.get(seg + 1).copied().unwrap_or(row.clusters.len());
+
+                let mut sx = 0;
+                for (cluster, &reverse) in row.clusters[start..end].iter().zip(&flags[start..end]) {
+                    let w = cluster_width(cluster);
+                    if sx + w > term_info.width {
+                        break;
+                    }
+                    line[sx] = Cell {
+                        grapheme: cluster.clone(),
+                        reverse,
+                        continuation: false,
+                    };
+                    for c in line.iter_mut().take(sx + w).skip(sx + 1) {
+                        *c = Cell {
+                            grapheme: String::new(),
+                            reverse,
+                            continuation: true,
+                        };
+                    }
+                    sx += w;
+                }
+
+                // advance to the next wrap segment, rolling onto the next row
+                seg += 1;
+                if seg >= starts.len() {
+                    row_index += 1;
+                    seg = 0;
+                    if row_index < self.rows.len() {
+                        starts = self.wrap_starts(row_index, width);
+                    }
+                }
+            }
+
+            frame.push(line);
+        }
+
+        Self::draw_search_prompt(&mut frame, term_info, mode, search);
+
+        frame
+    }
+
+    // build the frame for the current viewport, one `width`-column row per
+    // screen line. Clusters are laid out by display width (wide glyphs claim a
+    // trailing continuation column), search matches are flagged for reverse
+    // video, and in `Search` mode the bottom row shows the live query prompt.
+    fn render_frame(
+        &self,
+        term_info: &TermInfo,
+        cursor: &Cursor,
+        mode: Mode,
+        search: &Search,
+    ) -> Vec<Vec<Cell>> {
+        if cursor.wrap {
+            return self.render_wrapped(term_info, cursor, mode, search);
+        }
+
+        let mut frame = Vec::with_capacity(term_info.height);
+        let query: Vec<String> = search.query.graphemes(true).map(String::from).collect();
+
+        for y in 0..term_info.height {
+            let row_index = y + cursor.ren_row;
+            let mut line = vec![Cell::blank(); term_info.width];
+
+            if row_index < self.rows.len() {
+                let row = &self.rows[row_index];
+                let flags = self.highlights(row_index, &query);
+
+                // walk clusters by display column, placing each into the screen
+                // columns it covers once the horizontal scroll is subtracted
+                let mut dc = 0;
+                for (i, cluster) in row.clusters.iter().enumerate() {
+                    let w = cluster_width(cluster);
+                    // entirely scrolled off to the left
+                    if dc + w <= cursor.ren_col {
+                        dc += w;
+                        continue;
+                    }
+                    let sx = dc as isize - cursor.ren_col as isize;
+                    // past the right edge
+                    if sx >= term_info.width as isize {
+                        break;
+                    }
+                    // a wide glyph straddling an edge renders as blanks
+                    if sx < 0 || sx as usize + w > term_info.width {
+                        dc += w;
+                        continue;
+                    }
+
+                    let sx = sx as usize;
+                    line[sx] = Cell {
+                        grapheme: cluster.clone(),
+                        reverse: flags[i],
+                        continuation: false,
+                    };
+                    for c in line.iter_mut().take(sx + w).skip(sx + 1) {
+                        *c = Cell {
+                            grapheme: String::new(),
+                            reverse: flags[i],
+                            continuation: true,
+                        };
+                    }
+                    dc += w;
+                }
+            }
+
+            frame.push(line);
+        }
+
+        // draw the search prompt on the status (bottom) row
+        Self::draw_search_prompt(&mut frame, term_info, mode, search);
+
+        frame
+    }
+
+    fn draw_text(
+        &self,
+        term_info: &mut TermInfo,
+        cursor: &Cursor,
+        mode: Mode,
+        search: &Search,
+    ) -> Result<(), io::Error> {
+        let next_frame = self.render_frame(term_info, cursor, mode, search);
+
         // save cursor position and hide
         execute!(term_info.stdout, cursor::SavePosition)?;
         execute!(term_info.stdout, cursor::Hide)?;
 
-        // we need to render entire terminal screen
-        for y in 0..term_info.height {
-            let row_index = y + cursor.ren_row;
-            let mut line = vec![' '; term_info.width];
+        // diff against the previous frame and repaint only the cells that moved,
+        // batching each row's changes into contiguous runs
+        let mut dirty = false;
+        for (y, line) in next_frame.iter().enumerate() {
+            // a missing or mis-sized previous row forces the whole row to repaint
+            let prev = term_info.prev_frame.get(y).filter(|p| p.len() == line.len());
+
+            let mut x = 0;
+            while x < line.len() {
+                let unchanged = prev.map(|p| p[x] == line[x]).unwrap_or(false);
+                if unchanged {
+                    x += 1;
+                    continue;
+                }
 
-            // only print part of row that is visible
-            // rest we will print ' '
-            if row_index < self.rows.len() && cursor.ren_col < self.rows[row_index].chars.len() {
-                self.rows[row_index].chars[cursor.ren_col
-                    ..cmp::min(
-                        self.rows[row_index].chars.len(),
-                        cursor.ren_col + term_info.width,
-                    )]
-                    .iter()
-                    .enumerate()
-                    .for_each(|(i, c)| line[i] = *c);
+                // extend the run to the next unchanged cell
+                let start = x;
+                while x < line.len() && !prev.map(|p| p[x] == line[x]).unwrap_or(false) {
+                    x += 1;
+                }
+
+                // split the changed run into style-uniform segments so reverse
+                // video wraps only the highlighted cells; continuation columns
+                // are covered by their wide glyph and never emitted
+                queue!(term_info.stdout, cursor::MoveTo(start as u16, y as u16))?;
+                let mut seg = start;
+                while seg < x {
+                    let reverse = line[seg].reverse;
+                    let mut end = seg;
+                    let mut text = String::new();
+                    while end < x && line[end].reverse == reverse {
+                        if !line[end].continuation {
+                            text.push_str(&line[end].grapheme);
+                        }
+                        end += 1;
+                    }
+
+                    if reverse {
+                        queue!(term_info.stdout, style::SetAttribute(style::Attribute::Reverse))?;
+                        queue!(term_info.stdout, style::Print(text))?;
+                        queue!(term_info.stdout, style::SetAttribute(style::Attribute::Reset))?;
+                    } else {
+                        queue!(term_info.stdout, style::Print(text))?;
+                    }
+                    seg = end;
+                }
+                dirty = true;
             }
+        }
 
-            queue!(term_info.stdout, cursor::MoveTo(0, y.try_into().unwrap()))?;
-            queue!(
-                term_info.stdout,
-                style::Print(line.iter().collect::<String>())
-            )?;
+        // nothing changed: skip the explicit flush of the diffed cells. The
+        // cursor save/hide and restore/show commands around the diff go out
+        // through `execute!`, which flushes stdout on every draw regardless.
+        if dirty {
+            term_info.stdout.flush()?;
         }
 
-        term_info.stdout.flush()?;
+        term_info.prev_frame = next_frame;
 
         // restore cursor position and show
         execute!(term_info.stdout, cursor::RestorePosition)?;
@@ -304,7 +1494,7 @@ fn main() -> Result<(), io::Error> {
     let args = env::args().collect::<Vec<String>>();
 
     // init Text struct
-    let text = if args.len() > 1 {
+    let mut text = if args.len() > 1 {
         Text::from_file(args[1].to_owned().into())?
     } else {
         Text::new()?
@@ -313,19 +1503,63 @@ fn main() -> Result<(), io::Error> {
     // init Cursor struct
     let mut cursor = Cursor::new();
 
+    // start in normal mode
+    let mut mode = Mode::Normal;
+
+    // incremental search state
+    let mut search = Search::default();
+
+    // read terminal events on a dedicated thread so input latency is decoupled
+    // from the redraw cadence; the channel closes when this thread sees an error
+    // (e.g. stdin gone), which disconnects the receiver and ends the main loop
+    let (tx, rx) = mpsc::channel::<Event>();
+    thread::spawn(move || {
+        while let Ok(event) = read() {
+            if tx.send(event).is_err() {
+                break;
+            }
+        }
+    });
+
+    // paint the initial frame, then repaint only when an event arrives
+    text.draw_text(&mut term_info, &cursor, mode, &search)?;
+
     while term_info.alive {
-        // update term size
-        term_info.update_size()?;
+        // block briefly for the next event; a timeout just means the screen is
+        // idle, so we loop without repainting
+        let event = match rx.recv_timeout(Duration::from_millis(500)) {
+            Ok(event) => event,
+            Err(RecvTimeoutError::Timeout) => continue,
+            Err(RecvTimeoutError::Disconnected) => break,
+        };
 
-        // draw
-        text.draw_text(&mut term_info, &cursor)?;
+        // drain the event plus anything else already queued, so a burst of input
+        // or resizes collapses into a single redraw
+        let mut events = vec![event];
+        while let Ok(event) = rx.try_recv() {
+            events.push(event);
+        }
 
-        // handle input
-        if poll(Duration::from_millis(500))? {
-            if let Event::Key(key_event) = read()? {
-                cursor.handle_key_event(key_event, &mut term_info, &text)?;
+        for event in events {
+            match event {
+                Event::Key(key_event) => cursor.handle_key_event(
+                    key_event,
+                    &mut term_info,
+                    &mut text,
+                    &mut mode,
+                    &mut search,
+                )?,
+                Event::Resize(width, height) => {
+                    term_info.resize(width as usize, height as usize);
+                    // the new size may move the cursor off-screen (and in wrap
+                    // mode re-wraps every row), so re-settle the scroll offsets
+                    cursor.place_cursor(&mut term_info, &text)?;
+                }
+                _ => (),
             }
         }
+
+        text.draw_text(&mut term_info, &cursor, mode, &search)?;
     }
 
     Ok(())